@@ -1,19 +1,40 @@
 #![cfg_attr(not(feature = "std"), no_std)]
+#![allow(unexpected_cfgs)]
 
 use ink_lang as ink;
 
 #[ink::contract]
-mod a1Token {
+mod a1_token {
+    use ink_env::call::{build_call, Call, ExecutionInput, Selector};
+    use ink_prelude::string::String;
+    use ink_prelude::vec::Vec;
     use ink_storage::{traits::SpreadAllocate, Mapping};
 
     #[ink(storage)]
     #[derive(SpreadAllocate)]
     pub struct A1Token {
-        total_supply: u32,
-        balances: Mapping<AccountId, u32>,
-        // (Spender => Recipient) => amount 
-        allowances: Mapping<(AccountId, AccountId), u32>,
+        total_supply: Balance,
+        balances: Mapping<AccountId, Balance>,
+        // (Owner => Spender) => amount
+        allowances: Mapping<(AccountId, AccountId), Balance>,
         mint_authority: AccountId,
+        /// Compressed secp256k1 public key of the bridge signer allowed to authorize
+        /// cross-chain mints via `mint_with_receipt`.
+        bridge_signer: Vec<u8>,
+        /// Nonces already redeemed through `mint_with_receipt`, guarding against replay.
+        used_nonces: Mapping<u64, ()>,
+        /// Human-readable token name, e.g. "Aleph Zero Token".
+        name: String,
+        /// Human-readable token symbol, e.g. "A1T".
+        symbol: String,
+        /// Number of decimal places balances are displayed with.
+        decimals: u8,
+        /// Hard ceiling on `total_supply`, enforced by `mint` and `mint_with_receipt`.
+        /// `None` means unbounded. `Balance` rather than `u32` to match the
+        /// supply/balance type chunk0-2 widened everything else to.
+        max_supply: Option<Balance>,
+        /// Per-account minting quotas granted by `mint_authority` via `set_minter_quota`.
+        minters: Mapping<AccountId, Balance>,
     }
 
     #[ink(event)]
@@ -22,7 +43,7 @@ mod a1Token {
         sender: Option<AccountId>,
         #[ink(topic)]
         recipient: Option<AccountId>,
-        amount: u32,
+        amount: Balance,
     }
 
     #[ink(event)]
@@ -31,7 +52,14 @@ mod a1Token {
         owner: AccountId,
         #[ink(topic)]
         spender: AccountId,
-        amount: u32,
+        amount: Balance,
+    }
+
+    #[ink(event)]
+    pub struct MinterQuotaSet {
+        #[ink(topic)]
+        minter: AccountId,
+        quota: Balance,
     }
 
     /// Specify ERC-20 error type.
@@ -41,7 +69,18 @@ mod a1Token {
         /// Return if the balance cannot fulfill a request.
         InsufficientBalance,
         InsufficientAllowance,
-        Unauthorized
+        Unauthorized,
+        /// Returned if a bridge receipt's signature does not recover to `bridge_signer`.
+        BadSignature,
+        /// Returned if a bridge receipt's nonce has already been redeemed.
+        ReceiptAlreadyUsed,
+        /// Returned if a checked arithmetic operation would overflow or underflow.
+        Overflow,
+        /// Returned if the recipient's `on_token_received` callback reverted or does
+        /// not exist; the transfer is rolled back.
+        TransferCallFailed,
+        /// Returned if a mint would push `total_supply` past `max_supply`.
+        CapExceeded,
     }
 
     /// Specify the ERC-20 result type.
@@ -49,14 +88,49 @@ mod a1Token {
 
     use ink_lang::utils::initialize_contract;
     impl A1Token {
-        /// Creates a token contract with the given initial supply belonging to the contract creator.
+        /// Creates a token contract with the given initial supply belonging to the
+        /// contract creator, and registers the bridge signer authorized to sign
+        /// cross-chain mint receipts. Metadata is left empty; use
+        /// `new_token_with_metadata` to set a name, symbol and decimals.
         #[ink(constructor)]
-        pub fn new_token(initial_supply: u32) -> Self {
+        pub fn new_token(
+            initial_supply: Balance,
+            bridge_signer: [u8; 33],
+            max_supply: Option<Balance>,
+        ) -> Self {
+            Self::new_token_with_metadata(
+                initial_supply,
+                bridge_signer,
+                String::new(),
+                String::new(),
+                0,
+                max_supply,
+            )
+        }
+
+        /// Creates a token contract with the given initial supply belonging to the
+        /// contract creator, registers the bridge signer authorized to sign
+        /// cross-chain mint receipts, sets the token's display metadata, and fixes
+        /// the hard supply cap enforced by `mint` and `mint_with_receipt`.
+        #[ink(constructor)]
+        pub fn new_token_with_metadata(
+            initial_supply: Balance,
+            bridge_signer: [u8; 33],
+            name: String,
+            symbol: String,
+            decimals: u8,
+            max_supply: Option<Balance>,
+        ) -> Self {
             initialize_contract(|contract: &mut Self| {
                 let caller = Self::env().caller();
-                contract.balances.insert(&caller, &initial_supply);
+                contract.balances.insert(caller, &initial_supply);
                 contract.total_supply = initial_supply;
                 contract.mint_authority = caller;
+                contract.bridge_signer = bridge_signer.to_vec();
+                contract.name = name;
+                contract.symbol = symbol;
+                contract.decimals = decimals;
+                contract.max_supply = max_supply;
                 Self::env().emit_event(Transfer {
                     sender: None,
                     recipient: Some(caller),
@@ -65,19 +139,34 @@ mod a1Token {
             })
         }
 
+        /// Returns the token's human-readable name.
+        #[ink(message)]
+        pub fn token_name(&self) -> String {
+            self.name.clone()
+        }
+
+        /// Returns the token's human-readable symbol.
+        #[ink(message)]
+        pub fn token_symbol(&self) -> String {
+            self.symbol.clone()
+        }
+
+        /// Returns the number of decimal places balances are displayed with.
+        #[ink(message)]
+        pub fn token_decimals(&self) -> u8 {
+            self.decimals
+        }
+
         /// Returns the total token supply.
         #[ink(message)]
-        pub fn total_supply(&self) -> u32 {
+        pub fn total_supply(&self) -> Balance {
             self.total_supply
         }
 
         /// Checks the current balance of the chosen account.
         #[ink(message)]
-        pub fn balance_of(&self, account: AccountId) -> u32 {
-            match self.balances.get(&account) {
-                Some(value) => value,
-                None => 0,
-            }
+        pub fn balance_of(&self, account: AccountId) -> Balance {
+            self.balances.get(account).unwrap_or_default()
         }
 
         /// Checks the current mint authority.
@@ -86,33 +175,94 @@ mod a1Token {
             self.mint_authority
         }
 
+        /// Checks the current bridge signer.
+        #[ink(message)]
+        pub fn get_bridge_signer(&self) -> [u8; 33] {
+            self.bridge_signer_array()
+        }
+
         /// Transfers an amount of tokens to the chosen recipient.
         #[ink(message)]
-        pub fn transfer(&mut self, recipient: AccountId, amount: u32) -> Result<()> {
+        pub fn transfer(&mut self, recipient: AccountId, amount: Balance) -> Result<()> {
             let sender = self.env().caller();
             self.transfer_from_to(sender, recipient, amount)
         }
 
         /// Transfers an amount of tokens to the chosen recipient.
         #[ink(message)]
-        pub fn transfer_from_to(&mut self, sender: AccountId, recipient: AccountId, amount: u32) -> Result<()> {
+        pub fn transfer_from_to(&mut self, sender: AccountId, recipient: AccountId, amount: Balance) -> Result<()> {
             let sender_balance = self.balance_of(sender);
             if sender_balance < amount {
                 return Err(Error::InsufficientBalance);
             }
-            self.balances.insert(sender, &(sender_balance - amount));
+            let new_sender_balance = sender_balance.checked_sub(amount).ok_or(Error::Overflow)?;
+            self.balances.insert(sender, &new_sender_balance);
+            // Re-reads via `balance_of` so that sender == recipient nets out to a
+            // no-op instead of double-crediting off a stale pre-debit balance.
             let recipient_balance = self.balance_of(recipient);
-            self.balances.insert(recipient, &(recipient_balance + amount));
+            let new_recipient_balance = recipient_balance.checked_add(amount).ok_or(Error::Overflow)?;
+            self.balances.insert(recipient, &new_recipient_balance);
             self.env().emit_event(Transfer {
                 sender: Some(sender),
                 recipient: Some(recipient),
-                amount: amount,
+                amount,
             });
             Ok(())
         }
 
+        /// Transfers and invokes `recipient`'s `on_token_received` callback, crediting `recipient` only if it succeeds.
+        #[ink(message)]
+        pub fn transfer_and_call(
+            &mut self,
+            recipient: AccountId,
+            amount: Balance,
+            data: Vec<u8>,
+        ) -> Result<()> {
+            let sender = self.env().caller();
+
+            let sender_balance = self.balance_of(sender);
+            if sender_balance < amount {
+                return Err(Error::InsufficientBalance);
+            }
+            let new_sender_balance = sender_balance.checked_sub(amount).ok_or(Error::Overflow)?;
+            self.balances.insert(sender, &new_sender_balance);
+
+            let call_result = build_call::<ink_env::DefaultEnvironment>()
+                .call_type(Call::new().callee(recipient))
+                .exec_input(
+                    ExecutionInput::new(Selector::new(ink_lang::selector_bytes!(
+                        "on_token_received"
+                    )))
+                    .push_arg(sender)
+                    .push_arg(amount)
+                    .push_arg(data),
+                )
+                .returns::<()>()
+                .fire();
+
+            if call_result.is_err() {
+                self.balances.insert(sender, &sender_balance);
+                return Err(Error::TransferCallFailed);
+            }
+
+            // Re-read via `balance_of` rather than reusing a pre-call snapshot: the
+            // callback ran with `recipient` not yet credited, so this is the first
+            // write to `recipient`'s balance and is safe even if sender == recipient.
+            let recipient_balance = self.balance_of(recipient);
+            let new_recipient_balance = recipient_balance.checked_add(amount).ok_or(Error::Overflow)?;
+            self.balances.insert(recipient, &new_recipient_balance);
+
+            self.env().emit_event(Transfer {
+                sender: Some(sender),
+                recipient: Some(recipient),
+                amount,
+            });
+
+            Ok(())
+        }
+
         #[ink(message)]
-        pub fn approve(&mut self, spender: AccountId, amount: u32) -> Result<()>{
+        pub fn approve(&mut self, spender: AccountId, amount: Balance) -> Result<()>{
             let owner = self.env().caller();
             self.allowances.insert((owner, spender), &amount);
             self.env().emit_event(Approval {
@@ -124,48 +274,172 @@ mod a1Token {
         }
 
         #[ink(message)]
-        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> u32 {
-            match self.allowances.get((&owner, &spender)){
-                Some(value) => value,
-                None => 0,
-            }
+        pub fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
+            self.allowances.get((&owner, &spender)).unwrap_or_default()
         }
 
+        /// Increases the allowance granted to `spender` by `delta`.
         #[ink(message)]
-        pub fn transfer_from(&mut self, sender: AccountId, recipient: AccountId, amount: u32) -> Result<()> {
+        pub fn increase_allowance(&mut self, spender: AccountId, delta: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.allowance(owner, spender);
+            let new_allowance = allowance.checked_add(delta).ok_or(Error::Overflow)?;
+            self.allowances.insert((owner, spender), &new_allowance);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                amount: new_allowance,
+            });
+            Ok(())
+        }
+
+        /// Decreases the allowance granted to `spender` by `delta`.
+        #[ink(message)]
+        pub fn decrease_allowance(&mut self, spender: AccountId, delta: Balance) -> Result<()> {
+            let owner = self.env().caller();
+            let allowance = self.allowance(owner, spender);
+            let new_allowance = allowance.checked_sub(delta).ok_or(Error::Overflow)?;
+            self.allowances.insert((owner, spender), &new_allowance);
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                amount: new_allowance,
+            });
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn transfer_from(&mut self, sender: AccountId, recipient: AccountId, amount: Balance) -> Result<()> {
             let caller = self.env().caller();
             let allowance = self.allowance(sender, caller);
             if allowance < amount {
                 return Err(Error::InsufficientAllowance)
             }
             self.transfer_from_to(sender, recipient, amount)?;
-            self.allowances.insert((sender, caller), &(allowance - amount));
+            let new_allowance = allowance.checked_sub(amount).ok_or(Error::Overflow)?;
+            self.allowances.insert((sender, caller), &new_allowance);
             Ok(())
         }
-        
-        /// Mints more tokens if they are mint authority
+
+        /// Mints `amount` tokens to `recipient`, debiting the caller's minting quota.
+        /// Any account with a remaining quota granted via `set_minter_quota` may call
+        /// this; `mint_authority` also mints only up to a quota of its own.
         #[ink(message)]
-        pub fn mint(&mut self, amount: u32) -> Result<()> {
-            let sender = self.env().caller();
-            if sender != self.mint_authority {
+        pub fn mint(&mut self, recipient: AccountId, amount: Balance) -> Result<()> {
+            let caller = self.env().caller();
+            let quota = self.minters.get(caller).unwrap_or(0);
+            if quota < amount {
                 return Err(Error::Unauthorized);
             }
-            let sender_balance = self.balance_of(sender);
-            self.balances.insert(sender, &(sender_balance + amount));
-            self.total_supply += amount;
+            let new_total_supply = self.total_supply.checked_add(amount).ok_or(Error::Overflow)?;
+            if let Some(max_supply) = self.max_supply {
+                if new_total_supply > max_supply {
+                    return Err(Error::CapExceeded);
+                }
+            }
+
+            let new_quota = quota.checked_sub(amount).ok_or(Error::Overflow)?;
+            self.minters.insert(caller, &new_quota);
+
+            let recipient_balance = self.balance_of(recipient);
+            let new_recipient_balance = recipient_balance.checked_add(amount).ok_or(Error::Overflow)?;
+            self.balances.insert(recipient, &new_recipient_balance);
+            self.total_supply = new_total_supply;
+            Ok(())
+        }
+
+        /// Grants `minter` a minting quota of `quota` tokens. Only `mint_authority`
+        /// may call this.
+        #[ink(message)]
+        pub fn set_minter_quota(&mut self, minter: AccountId, quota: Balance) -> Result<()> {
+            if self.env().caller() != self.mint_authority {
+                return Err(Error::Unauthorized);
+            }
+            self.minters.insert(minter, &quota);
+            self.env().emit_event(MinterQuotaSet { minter, quota });
+            Ok(())
+        }
+
+        /// Mints tokens on presentation of a bridge receipt: a message signed by
+        /// `bridge_signer` authorizing `amount` tokens to be minted to `recipient`,
+        /// bound to this contract instance and to a single-use `nonce`.
+        #[ink(message)]
+        pub fn mint_with_receipt(
+            &mut self,
+            recipient: AccountId,
+            amount: Balance,
+            nonce: u64,
+            signature: [u8; 65],
+        ) -> Result<()> {
+            if self.used_nonces.contains(nonce) {
+                return Err(Error::ReceiptAlreadyUsed);
+            }
+
+            let message = (self.env().account_id(), recipient, amount, nonce);
+            let encoded = scale::Encode::encode(&message);
+            let mut hash = [0u8; 32];
+            ink_env::hash_bytes::<ink_env::hash::Blake2x256>(&encoded, &mut hash);
+
+            let mut recovered_signer = [0u8; 33];
+            ink_env::ecdsa_recover(&signature, &hash, &mut recovered_signer)
+                .map_err(|_| Error::BadSignature)?;
+            if recovered_signer != self.bridge_signer_array() {
+                return Err(Error::BadSignature);
+            }
+
+            let new_total_supply = self.total_supply.checked_add(amount).ok_or(Error::Overflow)?;
+            if let Some(max_supply) = self.max_supply {
+                if new_total_supply > max_supply {
+                    return Err(Error::CapExceeded);
+                }
+            }
+
+            self.used_nonces.insert(nonce, &());
+
+            let recipient_balance = self.balance_of(recipient);
+            let new_recipient_balance = recipient_balance.checked_add(amount).ok_or(Error::Overflow)?;
+            self.balances.insert(recipient, &new_recipient_balance);
+            self.total_supply = new_total_supply;
+
+            self.env().emit_event(Transfer {
+                sender: None,
+                recipient: Some(recipient),
+                amount,
+            });
+
+            Ok(())
+        }
+
+        /// Rotates the bridge signer allowed to authorize cross-chain mints.
+        #[ink(message)]
+        pub fn set_bridge_signer(&mut self, new_signer: [u8; 33]) -> Result<()> {
+            if self.env().caller() != self.mint_authority {
+                return Err(Error::Unauthorized);
+            }
+            self.bridge_signer = new_signer.to_vec();
             Ok(())
         }
 
+        /// `bridge_signer` is stored as a `Vec` (arrays beyond 32 bytes aren't
+        /// natively storage-compatible); this always holds exactly 33 bytes.
+        fn bridge_signer_array(&self) -> [u8; 33] {
+            let mut signer = [0u8; 33];
+            signer.copy_from_slice(&self.bridge_signer);
+            signer
+        }
+
         // Burns tokens
         #[ink(message)]
-        pub fn burn(&mut self, amount: u32) -> Result<()> {
+        pub fn burn(&mut self, amount: Balance) -> Result<()> {
             let sender = self.env().caller();
             let sender_balance = self.balance_of(sender);
             if sender_balance < amount {
                 return Err(Error::InsufficientBalance);
             }
-            self.balances.insert(sender, &(sender_balance - amount));
-            self.total_supply -= amount;
+            let new_sender_balance = sender_balance.checked_sub(amount).ok_or(Error::Overflow)?;
+            let new_total_supply = self.total_supply.checked_sub(amount).ok_or(Error::Overflow)?;
+            self.balances.insert(sender, &new_sender_balance);
+            self.total_supply = new_total_supply;
             Ok(())
         }
 
@@ -178,7 +452,7 @@ mod a1Token {
             }
             self.mint_authority = new_owner;
             Ok(())
-        }   
+        }
     }
 
     /// Unit tests in Rust are normally defined within such a `#[cfg(test)]`
@@ -191,61 +465,101 @@ mod a1Token {
 
         /// Imports `ink_lang` so we can use `#[ink::test]`.
         use ink_lang as ink;
+        use ink_lang::codegen::Env;
 
         /// We test if the default constructor does its job.
         #[ink::test]
         fn should_initialize_with_correct_supply() {
-            let A1Token = A1Token::new_token(1000);
-            assert_eq!(A1Token.total_supply, 1000);
+            let token = A1Token::new_token(1000, [0x03; 33], None);
+            assert_eq!(token.total_supply, 1000);
         }
 
         #[ink::test]
         fn should_allow_transfers() {
-            let mut A1Token = A1Token::new_token(1000);
+            let mut token = A1Token::new_token(1000, [0x03; 33], None);
             let alice = AccountId::from([0x1; 32]);
             let bob = AccountId::from([0x2; 32]);
 
-            let initial_bob_balance : u32 = A1Token.balance_of(bob);
+            let initial_bob_balance : Balance = token.balance_of(bob);
             assert_eq!(initial_bob_balance, 0);
 
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
-            let initial_alice_balance : u32 = A1Token.balance_of(alice);
+            let initial_alice_balance : Balance = token.balance_of(alice);
 
-            let amount_to_transfer : u32 = 250;
-            let success = A1Token.transfer(bob, amount_to_transfer);
+            let amount_to_transfer : Balance = 250;
+            let success = token.transfer(bob, amount_to_transfer);
+
+            let alice_balance_after : Balance = token.balance_of(alice);
+            let bob_balance_after : Balance = token.balance_of(bob);
 
-            let alice_balance_after : u32 = A1Token.balance_of(alice);
-            let bob_balance_after : u32 = A1Token.balance_of(bob);
-            
             assert_eq!(success, Ok(()));
             assert_eq!(alice_balance_after, initial_alice_balance - amount_to_transfer);
             assert_eq!(bob_balance_after, initial_bob_balance + amount_to_transfer);
         }
 
+        #[ink::test]
+        fn should_leave_balance_unchanged_on_self_transfer() {
+            let alice = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            let initial_supply : Balance = 1000;
+            let mut token = A1Token::new_token(initial_supply, [0x03; 33], None);
+
+            assert_eq!(token.transfer(alice, 500), Ok(()));
+            assert_eq!(token.balance_of(alice), initial_supply);
+        }
+
         #[ink::test]
         fn should_mint_more_supply() {
-            let amount_to_mint : u32 = 1000;
-            let mut A1Token = A1Token::new_token(amount_to_mint);
-            assert_eq!(A1Token.total_supply, amount_to_mint);
-            assert_eq!(A1Token.mint(amount_to_mint), Ok(()));
-            assert_eq!(A1Token.total_supply, amount_to_mint + amount_to_mint);
+            let alice = AccountId::from([0x1; 32]);
+            let bob = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            let amount_to_mint : Balance = 1000;
+            let mut token = A1Token::new_token(amount_to_mint, [0x03; 33], None);
+            assert_eq!(token.total_supply, amount_to_mint);
+
+            assert_eq!(token.set_minter_quota(alice, amount_to_mint), Ok(()));
+            assert_eq!(token.mint(alice, amount_to_mint), Ok(()));
+            assert_eq!(token.total_supply, amount_to_mint + amount_to_mint);
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
+            assert_eq!(token.mint(bob, amount_to_mint), Err(Error::Unauthorized));
+        }
 
+        #[ink::test]
+        fn should_enforce_minter_quota_and_supply_cap() {
+            let alice = AccountId::from([0x1; 32]);
             let bob = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            let initial_supply : Balance = 100;
+            let mut token = A1Token::new_token(initial_supply, [0x03; 33], Some(150));
+
+            assert_eq!(token.set_minter_quota(bob, 40), Ok(()));
+
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
-            assert_eq!(A1Token.mint(amount_to_mint), Err(Error::Unauthorized));
+            assert_eq!(token.mint(bob, 30), Ok(()));
+            assert_eq!(token.balance_of(bob), 30);
+            assert_eq!(token.mint(bob, 10), Ok(()));
+            assert_eq!(token.mint(bob, 1), Err(Error::Unauthorized));
+
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+            assert_eq!(token.set_minter_quota(alice, 100), Ok(()));
+            assert_eq!(token.mint(alice, 100), Err(Error::CapExceeded));
         }
 
         #[ink::test]
         fn should_burn_token(){
-            let initial_supply : u32 = 1000;
-            let mut A1Token = A1Token::new_token(initial_supply);
+            let initial_supply : Balance = 1000;
+            let mut token = A1Token::new_token(initial_supply, [0x03; 33], None);
 
-            let amount_to_burn : u32 = 250;
-            assert_eq!(A1Token.burn(amount_to_burn), Ok(()));
-            assert_eq!(A1Token.total_supply, initial_supply - amount_to_burn);
+            let amount_to_burn : Balance = 250;
+            assert_eq!(token.burn(amount_to_burn), Ok(()));
+            assert_eq!(token.total_supply, initial_supply - amount_to_burn);
 
-            assert_eq!(A1Token.burn(initial_supply), Err(Error::InsufficientBalance));
-            assert_eq!(A1Token.total_supply, initial_supply - amount_to_burn);
+            assert_eq!(token.burn(initial_supply), Err(Error::InsufficientBalance));
+            assert_eq!(token.total_supply, initial_supply - amount_to_burn);
         }
 
         #[ink::test]
@@ -256,16 +570,16 @@ mod a1Token {
 
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
 
-            let initial_supply : u32 = 1000;
-            let mut A1Token = A1Token::new_token(initial_supply);
+            let initial_supply : Balance = 1000;
+            let mut token = A1Token::new_token(initial_supply, [0x03; 33], None);
 
-            assert_eq!(A1Token.mint_authority, alice);
-            assert_eq!(A1Token.transfer_authority(bob), Ok(()));
-            assert_eq!(A1Token.mint_authority, bob);
+            assert_eq!(token.mint_authority, alice);
+            assert_eq!(token.transfer_authority(bob), Ok(()));
+            assert_eq!(token.mint_authority, bob);
 
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(jake);
-            assert_eq!(A1Token.transfer_authority(alice), Err(Error::Unauthorized));
-            assert_eq!(A1Token.mint_authority, bob);
+            assert_eq!(token.transfer_authority(alice), Err(Error::Unauthorized));
+            assert_eq!(token.mint_authority, bob);
         }
 
         #[ink::test]
@@ -274,12 +588,12 @@ mod a1Token {
             let bob = AccountId::from([0x2; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
 
-            let initial_supply : u32 = 1000;
-            let mut A1Token = A1Token::new_token(initial_supply);
+            let initial_supply : Balance = 1000;
+            let mut token = A1Token::new_token(initial_supply, [0x03; 33], None);
 
-            let amount_to_approve : u32 = 250;
-            assert_eq!(A1Token.approve(bob, amount_to_approve), Ok(())); 
-            assert_eq!(A1Token.allowance(alice, bob), amount_to_approve);           
+            let amount_to_approve : Balance = 250;
+            assert_eq!(token.approve(bob, amount_to_approve), Ok(()));
+            assert_eq!(token.allowance(alice, bob), amount_to_approve);
         }
 
         #[ink::test]
@@ -289,16 +603,140 @@ mod a1Token {
             let jake = AccountId::from([0x2; 32]);
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
 
-            let initial_supply : u32 = 1000;
-            let mut A1Token = A1Token::new_token(initial_supply);
-            assert_eq!(A1Token.balance_of(alice), initial_supply);
+            let initial_supply : Balance = 1000;
+            let mut token = A1Token::new_token(initial_supply, [0x03; 33], None);
+            assert_eq!(token.balance_of(alice), initial_supply);
 
-            let amount_to_transfer : u32 = 100;
-            A1Token.approve(bob, amount_to_transfer);
+            let amount_to_transfer : Balance = 100;
+            assert_eq!(token.approve(bob, amount_to_transfer), Ok(()));
 
             ink_env::test::set_caller::<ink_env::DefaultEnvironment>(bob);
-            A1Token.transfer_from(alice, jake, amount_to_transfer);
-            assert_eq!(A1Token.balance_of(jake), amount_to_transfer);
+            assert_eq!(token.transfer_from(alice, jake, amount_to_transfer), Ok(()));
+            assert_eq!(token.balance_of(jake), amount_to_transfer);
+        }
+
+        #[ink::test]
+        fn should_mint_with_valid_receipt_and_reject_replay() {
+            let secp = secp256k1::Secp256k1::new();
+            let secret_key = secp256k1::SecretKey::from_slice(&[0x42; 32]).unwrap();
+            let public_key = secp256k1::PublicKey::from_secret_key(&secp, &secret_key);
+            let bridge_signer = public_key.serialize();
+
+            let mut token = A1Token::new_token(1000, bridge_signer, None);
+            let contract_account = token.env().account_id();
+            let bob = AccountId::from([0x2; 32]);
+            let amount: Balance = 50;
+            let nonce: u64 = 1;
+
+            let message = (contract_account, bob, amount, nonce);
+            let encoded = scale::Encode::encode(&message);
+            let mut hash = [0u8; 32];
+            ink_env::hash_bytes::<ink_env::hash::Blake2x256>(&encoded, &mut hash);
+            let recoverable = secp.sign_ecdsa_recoverable(
+                &secp256k1::Message::from_slice(&hash).unwrap(),
+                &secret_key,
+            );
+            let (recovery_id, signature_bytes) = recoverable.serialize_compact();
+            let mut signature = [0u8; 65];
+            signature[..64].copy_from_slice(&signature_bytes);
+            signature[64] = recovery_id.to_i32() as u8;
+
+            assert_eq!(token.mint_with_receipt(bob, amount, nonce, signature), Ok(()));
+            assert_eq!(token.balance_of(bob), amount);
+
+            assert_eq!(
+                token.mint_with_receipt(bob, amount, nonce, signature),
+                Err(Error::ReceiptAlreadyUsed)
+            );
+        }
+
+        #[ink::test]
+        fn should_reject_forged_receipt_signature() {
+            let bridge_signer = [0x03; 33];
+            let mut token = A1Token::new_token(1000, bridge_signer, None);
+            let bob = AccountId::from([0x2; 32]);
+            // Recovery id (last byte) must be 0-3 or `ecdsa_recover` panics instead
+            // of erroring, so only the signature body is garbage.
+            let mut forged_signature = [0x11; 65];
+            forged_signature[64] = 0;
+
+            assert_eq!(
+                token.mint_with_receipt(bob, 50, 1, forged_signature),
+                Err(Error::BadSignature)
+            );
+        }
+
+        #[ink::test]
+        fn should_increase_and_decrease_allowance() {
+            let alice = AccountId::from([0x1; 32]);
+            let bob = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            let mut token = A1Token::new_token(1000, [0x03; 33], None);
+            assert_eq!(token.increase_allowance(bob, 100), Ok(()));
+            assert_eq!(token.allowance(alice, bob), 100);
+
+            assert_eq!(token.decrease_allowance(bob, 40), Ok(()));
+            assert_eq!(token.allowance(alice, bob), 60);
+
+            assert_eq!(token.decrease_allowance(bob, 1000), Err(Error::Overflow));
+            assert_eq!(token.allowance(alice, bob), 60);
+        }
+
+        #[ink::test]
+        fn should_expose_token_metadata() {
+            let token = A1Token::new_token_with_metadata(
+                1000,
+                [0x03; 33],
+                String::from("Aleph Zero Token"),
+                String::from("A1T"),
+                12,
+                None,
+            );
+            assert_eq!(token.token_name(), String::from("Aleph Zero Token"));
+            assert_eq!(token.token_symbol(), String::from("A1T"));
+            assert_eq!(token.token_decimals(), 12);
+
+            let plain_token = A1Token::new_token(1000, [0x03; 33], None);
+            assert_eq!(plain_token.token_name(), String::new());
+            assert_eq!(plain_token.token_decimals(), 0);
+        }
+
+        // ink_env's off-chain test engine doesn't implement cross-contract
+        // invocation at all (it panics rather than returning an `Err`), so these
+        // two can't run as unit tests; left in as documentation of the intended
+        // behavior pending e2e test support.
+        #[ink::test]
+        #[ignore]
+        fn should_roll_back_transfer_and_call_on_callback_failure() {
+            let alice = AccountId::from([0x1; 32]);
+            let bob = AccountId::from([0x2; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            let initial_supply: Balance = 1000;
+            let mut token = A1Token::new_token(initial_supply, [0x03; 33], None);
+
+            let amount_to_transfer: Balance = 100;
+            let result = token.transfer_and_call(bob, amount_to_transfer, Vec::new());
+
+            assert_eq!(result, Err(Error::TransferCallFailed));
+            assert_eq!(token.balance_of(alice), initial_supply);
+            assert_eq!(token.balance_of(bob), 0);
+        }
+
+        #[ink::test]
+        #[ignore]
+        fn should_leave_balance_unchanged_on_self_transfer_and_call_failure() {
+            let alice = AccountId::from([0x1; 32]);
+            ink_env::test::set_caller::<ink_env::DefaultEnvironment>(alice);
+
+            let initial_supply: Balance = 1000;
+            let mut token = A1Token::new_token(initial_supply, [0x03; 33], None);
+
+            let result = token.transfer_and_call(alice, 500, Vec::new());
+
+            assert_eq!(result, Err(Error::TransferCallFailed));
+            assert_eq!(token.balance_of(alice), initial_supply);
         }
 
     }